@@ -0,0 +1,22 @@
+/// Length in bytes of a serialized bn254 G1 point (x, y), big-endian.
+pub const G1_SIZE: usize = 64;
+/// Length in bytes of a serialized bn254 G2 point (x_c1, x_c0, y_c1, y_c0), big-endian.
+pub const G2_SIZE: usize = 128;
+/// Length in bytes of a bn254 scalar field element, big-endian.
+pub const FR_SIZE: usize = 32;
+
+/// Upper bound on the number of public inputs (and therefore `ic` entries,
+/// `ic.len() == public_inputs.len() + 1`) a registered circuit may have.
+/// Bounds `VerifyingKeyAccount`'s space so it can be sized with `InitSpace`.
+pub const MAX_PUBLIC_INPUTS: usize = 16;
+
+/// Upper bound on the `circuit_id` string, also used as a PDA seed.
+pub const MAX_CIRCUIT_ID_LEN: usize = 32;
+
+/// Upper bound on the number of proofs a single `verify_proof_batch` call may
+/// combine into one pairing check.
+pub const MAX_BATCH_SIZE: usize = 16;
+
+/// Upper bound on the number of guardians in a `GuardianSet`. Also the width
+/// of the `signed_guardians` bitmask on `VerificationAccount`.
+pub const MAX_GUARDIANS: usize = 32;
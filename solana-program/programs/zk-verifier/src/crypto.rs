@@ -0,0 +1,373 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::alt_bn128::prelude::{
+    alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing,
+};
+
+use crate::constants::{FR_SIZE, G1_SIZE, G2_SIZE};
+use crate::errors::ErrorCode;
+
+const ALT_BN128_ADDITION_INPUT_LEN: usize = 2 * G1_SIZE;
+const ALT_BN128_MULTIPLICATION_INPUT_LEN: usize = G1_SIZE + FR_SIZE;
+pub const ALT_BN128_PAIRING_ELEMENT_LEN: usize = G1_SIZE + G2_SIZE;
+
+/// bn254 base field modulus `q`, big-endian. Used to negate G1 points before
+/// feeding them to the pairing syscall (Solana has no dedicated negation op).
+const FQ_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// bn254 scalar field order `r` (the group order of G1/G2), big-endian. Used
+/// to fold the per-proof batch scalars into a single combined scalar.
+const FR_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Computes `vk_x = ic[0] + sum_i public_inputs[i] * ic[i+1]` using the
+/// alt_bn128 G1 addition and scalar-multiplication syscalls.
+pub fn compute_vk_x(
+    ic: &[[u8; G1_SIZE]],
+    public_inputs: &[[u8; FR_SIZE]],
+) -> Result<[u8; G1_SIZE]> {
+    let mut vk_x = ic[0];
+    for (i, x) in public_inputs.iter().enumerate() {
+        let term = alt_bn128_mul(&ic[i + 1], x)?;
+        vk_x = alt_bn128_add(&vk_x, &term)?;
+    }
+    Ok(vk_x)
+}
+
+/// Checks `e(a,b) * e(neg_vk_x,gamma) * e(neg_c,delta) * e(neg_alpha,beta) == 1`
+/// with a single `sol_alt_bn128_pairing` call over the four point pairs.
+#[allow(clippy::too_many_arguments)]
+pub fn check_groth16_pairing(
+    proof_a: &[u8; G1_SIZE],
+    proof_b: &[u8; G2_SIZE],
+    vk_x: &[u8; G1_SIZE],
+    vk_gamma_g2: &[u8; G2_SIZE],
+    proof_c: &[u8; G1_SIZE],
+    vk_delta_g2: &[u8; G2_SIZE],
+    vk_alpha_g1: &[u8; G1_SIZE],
+    vk_beta_g2: &[u8; G2_SIZE],
+) -> Result<bool> {
+    let neg_vk_x = negate_g1(vk_x);
+    let neg_c = negate_g1(proof_c);
+    let neg_alpha = negate_g1(vk_alpha_g1);
+
+    let mut input = Vec::with_capacity(ALT_BN128_PAIRING_ELEMENT_LEN * 4);
+    input.extend_from_slice(proof_a);
+    input.extend_from_slice(proof_b);
+    input.extend_from_slice(&neg_vk_x);
+    input.extend_from_slice(vk_gamma_g2);
+    input.extend_from_slice(&neg_c);
+    input.extend_from_slice(vk_delta_g2);
+    input.extend_from_slice(&neg_alpha);
+    input.extend_from_slice(vk_beta_g2);
+
+    let output = alt_bn128_pairing(&input).map_err(|_| error!(ErrorCode::PairingCheckFailed))?;
+
+    let mut expected_true = [0u8; 32];
+    expected_true[31] = 1;
+    Ok(output == expected_true)
+}
+
+/// Calls the `sol_alt_bn128_group_op` G1 addition syscall.
+pub fn alt_bn128_add(a: &[u8; G1_SIZE], b: &[u8; G1_SIZE]) -> Result<[u8; G1_SIZE]> {
+    let mut input = [0u8; ALT_BN128_ADDITION_INPUT_LEN];
+    input[..G1_SIZE].copy_from_slice(a);
+    input[G1_SIZE..].copy_from_slice(b);
+    let output = alt_bn128_addition(&input).map_err(|_| error!(ErrorCode::PairingCheckFailed))?;
+    let mut result = [0u8; G1_SIZE];
+    result.copy_from_slice(&output);
+    Ok(result)
+}
+
+/// Calls the `sol_alt_bn128_group_op` G1 scalar-multiplication syscall.
+pub fn alt_bn128_mul(point: &[u8; G1_SIZE], scalar: &[u8; FR_SIZE]) -> Result<[u8; G1_SIZE]> {
+    let mut input = [0u8; ALT_BN128_MULTIPLICATION_INPUT_LEN];
+    input[..G1_SIZE].copy_from_slice(point);
+    input[G1_SIZE..].copy_from_slice(scalar);
+    let output =
+        alt_bn128_multiplication(&input).map_err(|_| error!(ErrorCode::PairingCheckFailed))?;
+    let mut result = [0u8; G1_SIZE];
+    result.copy_from_slice(&output);
+    Ok(result)
+}
+
+/// Negates a G1 point (`y' = q - y`) so it can be folded into the single
+/// multi-pairing check; there is no dedicated negation syscall.
+pub fn negate_g1(point: &[u8; G1_SIZE]) -> [u8; G1_SIZE] {
+    let y = &point[32..64];
+    if y.iter().all(|&b| b == 0) {
+        return *point;
+    }
+    let mut result = *point;
+    result[32..64].copy_from_slice(&fq_sub(&FQ_MODULUS, y));
+    result
+}
+
+/// Big-endian 256-bit subtraction `a - b`, assuming `a >= b`.
+pub fn fq_sub(a: &[u8; 32], b: &[u8]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    let mut borrow: i16 = 0;
+    for i in (0..32).rev() {
+        let diff = a[i] as i16 - b[i] as i16 - borrow;
+        if diff < 0 {
+            result[i] = (diff + 256) as u8;
+            borrow = 1;
+        } else {
+            result[i] = diff as u8;
+            borrow = 0;
+        }
+    }
+    result
+}
+
+/// Hashes the public input vector so a fixed-size digest can be stored on the
+/// verification account regardless of how many signals the circuit exposes.
+pub fn hash_public_inputs(public_inputs: &[[u8; FR_SIZE]]) -> [u8; 32] {
+    let slices: Vec<&[u8]> = public_inputs.iter().map(|x| x.as_ref()).collect();
+    anchor_lang::solana_program::keccak::hashv(&slices).to_bytes()
+}
+
+/// Reduces an arbitrary 256-bit big-endian value modulo the bn254 group
+/// order `r`, one bit at a time. Needed before folding a raw keccak digest
+/// into `fr_add_mod`/`alt_bn128_mul`, since a digest can be up to ~5.3x `r`
+/// and `fr_add_mod` only assumes its inputs are already-reduced residues.
+pub fn fr_reduce(value: &[u8; FR_SIZE]) -> [u8; FR_SIZE] {
+    let mut remainder = [0u8; FR_SIZE];
+    for byte in value {
+        for bit in (0..8).rev() {
+            // remainder = remainder * 2 + next_bit
+            let mut carry = (byte >> bit) & 1;
+            for i in (0..FR_SIZE).rev() {
+                let shifted = ((remainder[i] as u16) << 1) | carry as u16;
+                remainder[i] = (shifted & 0xff) as u8;
+                carry = (shifted >> 8) as u8;
+            }
+            if remainder >= FR_MODULUS {
+                remainder = fq_sub(&remainder, &FR_MODULUS);
+            }
+        }
+    }
+    remainder
+}
+
+/// Adds two scalars modulo the bn254 group order `r`. Used to fold a batch's
+/// per-proof random scalars into the single combined scalar the batch
+/// verification equation multiplies `alpha` by. Assumes both inputs are
+/// already-reduced residues (i.e. `< FR_MODULUS`); callers deriving scalars
+/// from a hash must reduce them first with `fr_reduce`.
+pub fn fr_add_mod(a: &[u8; FR_SIZE], b: &[u8; FR_SIZE]) -> [u8; FR_SIZE] {
+    let mut sum = vec![0u8; FR_SIZE + 1];
+    let mut carry: u16 = 0;
+    for i in (0..FR_SIZE).rev() {
+        let s = a[i] as u16 + b[i] as u16 + carry;
+        sum[i + 1] = (s & 0xff) as u8;
+        carry = s >> 8;
+    }
+    sum[0] = carry as u8;
+
+    let mut modulus = vec![0u8; FR_SIZE + 1];
+    modulus[1..].copy_from_slice(&FR_MODULUS);
+
+    if sum >= modulus {
+        let mut borrow: i16 = 0;
+        for i in (0..sum.len()).rev() {
+            let diff = sum[i] as i16 - modulus[i] as i16 - borrow;
+            if diff < 0 {
+                sum[i] = (diff + 256) as u8;
+                borrow = 1;
+            } else {
+                sum[i] = diff as u8;
+                borrow = 0;
+            }
+        }
+    }
+
+    let mut result = [0u8; FR_SIZE];
+    result.copy_from_slice(&sum[1..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixtures exercising `check_groth16_pairing`/`compute_vk_x` against real
+    /// bn254 points instead of just the `fr_reduce`/`fr_add_mod` field
+    /// arithmetic below. `solana_program::alt_bn128` falls back to a real
+    /// `ark_bn254`-backed implementation off-chain, so these run genuine
+    /// curve/pairing math, not a mock.
+    mod groth16_fixture {
+        use super::*;
+        use ark_bn254::{Fr, G1Affine, G2Affine};
+        use ark_ec::{AffineRepr, CurveGroup};
+        use ark_ff::{BigInteger, PrimeField};
+        use ark_serialize::{CanonicalSerialize, Compress};
+
+        fn g1_to_bytes(p: &G1Affine) -> [u8; G1_SIZE] {
+            let mut native = [0u8; G1_SIZE];
+            p.serialize_with_mode(&mut native[..], Compress::No).unwrap();
+            let mut be = [0u8; G1_SIZE];
+            for (chunk_out, chunk_in) in be.chunks_mut(32).zip(native.chunks(32)) {
+                chunk_out.copy_from_slice(chunk_in);
+                chunk_out.reverse();
+            }
+            be
+        }
+
+        fn g2_to_bytes(p: &G2Affine) -> [u8; G2_SIZE] {
+            let mut native = [0u8; G2_SIZE];
+            p.serialize_with_mode(&mut native[..], Compress::No).unwrap();
+            let mut be = [0u8; G2_SIZE];
+            for (chunk_out, chunk_in) in be.chunks_mut(64).zip(native.chunks(64)) {
+                chunk_out.copy_from_slice(chunk_in);
+                chunk_out.reverse();
+            }
+            be
+        }
+
+        fn fr_to_bytes(x: u64) -> [u8; FR_SIZE] {
+            Fr::from(x).into_bigint().to_bytes_be().try_into().unwrap()
+        }
+
+        /// A verifying key/proof pair that satisfies the pairing equation by
+        /// construction rather than by running a real circuit: `vk_x` is
+        /// pinned to the identity (`ic[0] = 0`, `public_inputs = [0]`) and
+        /// `proof_c = 0`, so `e(-vk_x,gamma)` and `e(-C,delta)` both collapse
+        /// to 1 regardless of `gamma`/`delta`, leaving `e(alpha,beta) *
+        /// e(-alpha,beta) == 1` — true for any `alpha`/`beta`. This still
+        /// exercises the real point encoding, negation, and
+        /// `sol_alt_bn128_pairing` call, just without needing a
+        /// circuit-compiler-generated proof.
+        struct Fixture {
+            alpha_g1: [u8; G1_SIZE],
+            beta_g2: [u8; G2_SIZE],
+            gamma_g2: [u8; G2_SIZE],
+            delta_g2: [u8; G2_SIZE],
+            ic: [[u8; G1_SIZE]; 2],
+            proof_a: [u8; G1_SIZE],
+            proof_b: [u8; G2_SIZE],
+            proof_c: [u8; G1_SIZE],
+            public_inputs: [[u8; FR_SIZE]; 1],
+        }
+
+        fn fixture() -> Fixture {
+            let alpha = G1Affine::generator();
+            let beta = G2Affine::generator();
+            let gamma = (G2Affine::generator() + G2Affine::generator()).into_affine();
+            let delta = (gamma + G2Affine::generator()).into_affine();
+            let ic1 = (G1Affine::generator() + G1Affine::generator()).into_affine();
+
+            Fixture {
+                alpha_g1: g1_to_bytes(&alpha),
+                beta_g2: g2_to_bytes(&beta),
+                gamma_g2: g2_to_bytes(&gamma),
+                delta_g2: g2_to_bytes(&delta),
+                ic: [[0u8; G1_SIZE], g1_to_bytes(&ic1)],
+                proof_a: g1_to_bytes(&alpha),
+                proof_b: g2_to_bytes(&beta),
+                proof_c: [0u8; G1_SIZE],
+                public_inputs: [fr_to_bytes(0)],
+            }
+        }
+
+        #[test]
+        fn valid_proof_passes_the_pairing_check() {
+            let f = fixture();
+            let vk_x = compute_vk_x(&f.ic, &f.public_inputs).unwrap();
+            assert_eq!(vk_x, [0u8; G1_SIZE]);
+
+            let ok = check_groth16_pairing(
+                &f.proof_a,
+                &f.proof_b,
+                &vk_x,
+                &f.gamma_g2,
+                &f.proof_c,
+                &f.delta_g2,
+                &f.alpha_g1,
+                &f.beta_g2,
+            )
+            .unwrap();
+            assert!(ok);
+        }
+
+        #[test]
+        fn tampered_proof_a_fails_the_pairing_check() {
+            let f = fixture();
+            let vk_x = compute_vk_x(&f.ic, &f.public_inputs).unwrap();
+            let tampered_a = g1_to_bytes(&(G1Affine::generator() + G1Affine::generator()).into_affine());
+
+            let ok = check_groth16_pairing(
+                &tampered_a,
+                &f.proof_b,
+                &vk_x,
+                &f.gamma_g2,
+                &f.proof_c,
+                &f.delta_g2,
+                &f.alpha_g1,
+                &f.beta_g2,
+            )
+            .unwrap();
+            assert!(!ok);
+        }
+
+        #[test]
+        fn tampered_public_input_fails_the_pairing_check() {
+            let f = fixture();
+            // Shifts vk_x away from the identity, which the fixture relies on
+            // to make the unrelated gamma/delta pairs collapse to 1.
+            let tampered_inputs = [fr_to_bytes(1)];
+            let vk_x = compute_vk_x(&f.ic, &tampered_inputs).unwrap();
+            assert_ne!(vk_x, [0u8; G1_SIZE]);
+
+            let ok = check_groth16_pairing(
+                &f.proof_a,
+                &f.proof_b,
+                &vk_x,
+                &f.gamma_g2,
+                &f.proof_c,
+                &f.delta_g2,
+                &f.alpha_g1,
+                &f.beta_g2,
+            )
+            .unwrap();
+            assert!(!ok);
+        }
+    }
+
+    #[test]
+    fn fr_reduce_caps_full_range_values_below_modulus() {
+        let max = [0xffu8; FR_SIZE];
+        let reduced = fr_reduce(&max);
+        assert!(reduced < FR_MODULUS);
+
+        let zero = [0u8; FR_SIZE];
+        assert_eq!(fr_reduce(&zero), zero);
+    }
+
+    #[test]
+    fn fr_add_mod_wraps_around_the_modulus() {
+        // FR_MODULUS - 1, so adding 1 should wrap to 0.
+        let mut modulus_minus_one = FR_MODULUS;
+        modulus_minus_one[FR_SIZE - 1] -= 1;
+        let mut one = [0u8; FR_SIZE];
+        one[FR_SIZE - 1] = 1;
+
+        assert_eq!(fr_add_mod(&modulus_minus_one, &one), [0u8; FR_SIZE]);
+    }
+
+    #[test]
+    fn fr_add_mod_of_two_reduced_full_range_scalars_stays_below_modulus() {
+        // Before the fix, derive_batch_scalars fed raw (unreduced) digests
+        // like these straight into fr_add_mod, which silently truncated the
+        // overflow instead of reducing it. Reducing first keeps the result a
+        // valid residue no matter how large the original digests were.
+        let a = fr_reduce(&[0xffu8; FR_SIZE]);
+        let b = fr_reduce(&[0xabu8; FR_SIZE]);
+
+        assert!(fr_add_mod(&a, &b) < FR_MODULUS);
+    }
+}
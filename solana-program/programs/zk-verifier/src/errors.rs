@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Invalid public signals provided")]
+    InvalidPublicSignals,
+    #[msg("Verifying key does not match the number of public inputs")]
+    InvalidVerifyingKey,
+    #[msg("Groth16 pairing check failed")]
+    PairingCheckFailed,
+    #[msg("circuit_id exceeds the maximum length")]
+    CircuitIdTooLong,
+    #[msg("ic vector exceeds the maximum number of public inputs")]
+    TooManyPublicInputs,
+    #[msg("Only the verifier-state authority may perform this action")]
+    Unauthorized,
+    #[msg("This circuit has been deactivated")]
+    CircuitInactive,
+    #[msg("Batch exceeds the maximum number of proofs")]
+    BatchTooLarge,
+    #[msg("Number of remaining accounts does not match the number of proofs")]
+    BatchAccountMismatch,
+    #[msg("Groth16 batch pairing check failed")]
+    BatchPairingFailed,
+    #[msg("Invalid guardian threshold")]
+    InvalidThreshold,
+    #[msg("Guardian set exceeds the maximum number of guardians")]
+    TooManyGuardians,
+    #[msg("Guardian set epochs must be registered sequentially")]
+    GuardianEpochMismatch,
+    #[msg("Signer is not a guardian at the given index for this proof's guardian set")]
+    NotAGuardian,
+    #[msg("This guardian has already attested this proof")]
+    GuardianAlreadySigned,
+    #[msg("This verification has already been finalized")]
+    AlreadyFinalized,
+    #[msg("Declared nullifier does not match the proof's public inputs")]
+    NullifierMismatch,
+    #[msg("This nullifier has already been spent")]
+    NullifierAlreadyUsed,
+    #[msg("Proof has not passed verification and cannot be attested")]
+    ProofNotVerified,
+    #[msg("Guardian set contains a duplicate guardian pubkey")]
+    DuplicateGuardian,
+}
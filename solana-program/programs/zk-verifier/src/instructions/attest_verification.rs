@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{GuardianSet, VerificationAccount};
+
+#[derive(Accounts)]
+#[instruction(proof_hash: [u8; 32], guardian_index: u8)]
+pub struct AttestVerification<'info> {
+    #[account(
+        mut,
+        seeds = [b"verification", proof_hash.as_ref()],
+        bump = verification_account.bump,
+    )]
+    pub verification_account: Account<'info, VerificationAccount>,
+
+    #[account(
+        seeds = [b"guardian-set", verification_account.guardian_epoch.to_le_bytes().as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    pub guardian: Signer<'info>,
+}
+
+/// A guardian attests that it has independently checked a verified proof.
+/// Only a `VerificationAccount` whose `verified` flag is set can be
+/// attested, so a proof that was merely rejected can't be pushed to
+/// `finalized` by a guardian quorum. Tracks which guardian indices have
+/// signed as a bitmask and flips `finalized` once `threshold` distinct
+/// guardians have attested.
+pub(crate) fn handler(
+    ctx: Context<AttestVerification>,
+    _proof_hash: [u8; 32],
+    guardian_index: u8,
+) -> Result<()> {
+    let guardian_set = &ctx.accounts.guardian_set;
+    let verification_account = &mut ctx.accounts.verification_account;
+
+    require!(!verification_account.finalized, ErrorCode::AlreadyFinalized);
+    require!(verification_account.verified, ErrorCode::ProofNotVerified);
+    require!(
+        (guardian_index as usize) < guardian_set.guardians.len(),
+        ErrorCode::NotAGuardian
+    );
+    require_keys_eq!(
+        guardian_set.guardians[guardian_index as usize],
+        ctx.accounts.guardian.key(),
+        ErrorCode::NotAGuardian
+    );
+
+    let mask = 1u32 << guardian_index;
+    require!(
+        verification_account.signed_guardians & mask == 0,
+        ErrorCode::GuardianAlreadySigned
+    );
+    verification_account.signed_guardians |= mask;
+
+    if verification_account.signed_guardians.count_ones() as u8 >= guardian_set.threshold {
+        verification_account.finalized = true;
+    }
+
+    msg!(
+        "Guardian {} attested proof_hash={:?}",
+        guardian_index,
+        verification_account.proof_hash
+    );
+
+    Ok(())
+}
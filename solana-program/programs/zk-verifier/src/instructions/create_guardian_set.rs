@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_GUARDIANS;
+use crate::errors::ErrorCode;
+use crate::state::{GuardianSet, VerifierState};
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct CreateGuardianSet<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GuardianSet::INIT_SPACE,
+        seeds = [b"guardian-set", epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier-state"],
+        bump,
+        constraint = verifier_state.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub verifier_state: Account<'info, VerifierState>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a new guardian-set epoch, rotating which guardians may attest
+/// future proofs. Past epochs are left untouched so proofs already verified
+/// under them can still be attested against the set that was active then.
+///
+/// `guardians` must not contain duplicates: `attest_verification` tracks
+/// signatures per index, so the same pubkey placed at two indices could
+/// otherwise satisfy an M-of-N threshold by itself.
+pub(crate) fn handler(
+    ctx: Context<CreateGuardianSet>,
+    epoch: u64,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        epoch == ctx.accounts.verifier_state.current_guardian_epoch + 1,
+        ErrorCode::GuardianEpochMismatch
+    );
+    require!(!guardians.is_empty(), ErrorCode::InvalidThreshold);
+    require!(guardians.len() <= MAX_GUARDIANS, ErrorCode::TooManyGuardians);
+    require!(
+        threshold > 0 && (threshold as usize) <= guardians.len(),
+        ErrorCode::InvalidThreshold
+    );
+    for (i, guardian) in guardians.iter().enumerate() {
+        require!(
+            !guardians[..i].contains(guardian),
+            ErrorCode::DuplicateGuardian
+        );
+    }
+
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    guardian_set.epoch = epoch;
+    guardian_set.threshold = threshold;
+    guardian_set.guardians = guardians;
+    guardian_set.authority = ctx.accounts.authority.key();
+    guardian_set.bump = ctx.bumps.guardian_set;
+
+    ctx.accounts.verifier_state.current_guardian_epoch = epoch;
+
+    msg!(
+        "Guardian set epoch {} created, threshold {}",
+        epoch,
+        threshold
+    );
+
+    Ok(())
+}
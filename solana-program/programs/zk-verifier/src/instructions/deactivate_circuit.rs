@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ErrorCode;
+use crate::state::{VerifierState, VerifyingKeyAccount};
+
+#[derive(Accounts)]
+#[instruction(circuit_id: String)]
+pub struct DeactivateCircuit<'info> {
+    #[account(
+        mut,
+        seeds = [b"vk", circuit_id.as_bytes()],
+        bump = verifying_key.bump,
+    )]
+    pub verifying_key: Account<'info, VerifyingKeyAccount>,
+
+    #[account(
+        seeds = [b"verifier-state"],
+        bump,
+        constraint = verifier_state.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub verifier_state: Account<'info, VerifierState>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Marks a registered circuit inactive so `verify_proof` stops accepting
+/// proofs against it, without reclaiming the PDA.
+pub(crate) fn handler(ctx: Context<DeactivateCircuit>, _circuit_id: String) -> Result<()> {
+    let verifying_key = &mut ctx.accounts.verifying_key;
+    verifying_key.active = false;
+    msg!("Circuit deactivated: {}", verifying_key.circuit_id);
+    Ok(())
+}
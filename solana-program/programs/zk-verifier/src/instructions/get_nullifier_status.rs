@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(circuit_id: String, nullifier: [u8; 32])]
+pub struct GetNullifierStatus<'info> {
+    /// CHECK: existence-only check; the PDA may not have been created yet.
+    #[account(
+        seeds = [b"nullifier", circuit_id.as_bytes(), nullifier.as_ref()],
+        bump
+    )]
+    pub nullifier_account: UncheckedAccount<'info>,
+}
+
+/// Query whether a circuit's commitment/public-signal field has already
+/// been spent by a verified proof.
+pub(crate) fn handler(
+    ctx: Context<GetNullifierStatus>,
+    _circuit_id: String,
+    _nullifier: [u8; 32],
+) -> Result<bool> {
+    Ok(!ctx.accounts.nullifier_account.data_is_empty())
+}
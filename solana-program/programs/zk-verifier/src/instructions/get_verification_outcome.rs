@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{VerificationAccount, VerificationOutcome};
+
+#[derive(Accounts)]
+pub struct GetVerificationOutcome<'info> {
+    pub verification_account: Account<'info, VerificationAccount>,
+}
+
+/// Query why a proof was or wasn't accepted, as a typed outcome rather than
+/// the ambiguous `verified` bool.
+pub(crate) fn handler(ctx: Context<GetVerificationOutcome>) -> Result<VerificationOutcome> {
+    Ok(ctx.accounts.verification_account.outcome)
+}
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VerificationAccount;
+
+#[derive(Accounts)]
+pub struct GetVerificationStatus<'info> {
+    pub verification_account: Account<'info, VerificationAccount>,
+}
+
+pub(crate) fn handler(ctx: Context<GetVerificationStatus>) -> Result<bool> {
+    Ok(ctx.accounts.verification_account.verified)
+}
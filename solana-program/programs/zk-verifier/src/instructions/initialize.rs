@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::state::VerifierState;
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VerifierState::INIT_SPACE,
+        seeds = [b"verifier-state"],
+        bump
+    )]
+    pub verifier_state: Account<'info, VerifierState>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub(crate) fn handler(ctx: Context<Initialize>) -> Result<()> {
+    let verifier_state = &mut ctx.accounts.verifier_state;
+    verifier_state.authority = ctx.accounts.authority.key();
+    verifier_state.total_verifications = 0;
+    verifier_state.current_guardian_epoch = 0;
+    msg!("ZK Verifier initialized");
+    Ok(())
+}
@@ -0,0 +1,23 @@
+pub mod attest_verification;
+pub mod create_guardian_set;
+pub mod deactivate_circuit;
+pub mod get_nullifier_status;
+pub mod get_verification_outcome;
+pub mod get_verification_status;
+pub mod initialize;
+pub mod register_circuit;
+pub mod update_circuit;
+pub mod verify_proof;
+pub mod verify_proof_batch;
+
+pub use attest_verification::*;
+pub use create_guardian_set::*;
+pub use deactivate_circuit::*;
+pub use get_nullifier_status::*;
+pub use get_verification_outcome::*;
+pub use get_verification_status::*;
+pub use initialize::*;
+pub use register_circuit::*;
+pub use update_circuit::*;
+pub use verify_proof::*;
+pub use verify_proof_batch::*;
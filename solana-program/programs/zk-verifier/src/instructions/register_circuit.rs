@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{G1_SIZE, G2_SIZE, MAX_CIRCUIT_ID_LEN, MAX_PUBLIC_INPUTS};
+use crate::errors::ErrorCode;
+use crate::state::{VerifierState, VerifyingKeyAccount};
+
+#[derive(Accounts)]
+#[instruction(circuit_id: String)]
+pub struct RegisterCircuit<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VerifyingKeyAccount::INIT_SPACE,
+        seeds = [b"vk", circuit_id.as_bytes()],
+        bump
+    )]
+    pub verifying_key: Account<'info, VerifyingKeyAccount>,
+
+    #[account(
+        seeds = [b"verifier-state"],
+        bump,
+        constraint = verifier_state.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub verifier_state: Account<'info, VerifierState>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a new circuit's Groth16 verifying key in a PDA seeded by
+/// `circuit_id`, so `verify_proof` can later look it up without redeploying
+/// the program for every application (e.g. a membership circuit vs. a
+/// threshold circuit). `ic` must be non-empty (`ic[0]` is required even for a
+/// circuit with zero public inputs), otherwise the circuit could never be
+/// satisfied by any future `verify_proof`/`verify_proof_batch` call.
+pub(crate) fn handler(
+    ctx: Context<RegisterCircuit>,
+    circuit_id: String,
+    alpha_g1: [u8; G1_SIZE],
+    beta_g2: [u8; G2_SIZE],
+    gamma_g2: [u8; G2_SIZE],
+    delta_g2: [u8; G2_SIZE],
+    ic: Vec<[u8; G1_SIZE]>,
+) -> Result<()> {
+    require!(
+        circuit_id.len() <= MAX_CIRCUIT_ID_LEN,
+        ErrorCode::CircuitIdTooLong
+    );
+    require!(!ic.is_empty(), ErrorCode::InvalidVerifyingKey);
+    require!(
+        ic.len() <= MAX_PUBLIC_INPUTS + 1,
+        ErrorCode::TooManyPublicInputs
+    );
+
+    let verifying_key = &mut ctx.accounts.verifying_key;
+    verifying_key.circuit_id = circuit_id;
+    verifying_key.authority = ctx.accounts.authority.key();
+    verifying_key.alpha_g1 = alpha_g1;
+    verifying_key.beta_g2 = beta_g2;
+    verifying_key.gamma_g2 = gamma_g2;
+    verifying_key.delta_g2 = delta_g2;
+    verifying_key.ic = ic;
+    verifying_key.active = true;
+    verifying_key.bump = ctx.bumps.verifying_key;
+
+    msg!("Circuit registered: {}", verifying_key.circuit_id);
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{G1_SIZE, G2_SIZE, MAX_PUBLIC_INPUTS};
+use crate::errors::ErrorCode;
+use crate::state::{VerifierState, VerifyingKeyAccount};
+
+#[derive(Accounts)]
+#[instruction(circuit_id: String)]
+pub struct UpdateCircuit<'info> {
+    #[account(
+        mut,
+        seeds = [b"vk", circuit_id.as_bytes()],
+        bump = verifying_key.bump,
+    )]
+    pub verifying_key: Account<'info, VerifyingKeyAccount>,
+
+    #[account(
+        seeds = [b"verifier-state"],
+        bump,
+        constraint = verifier_state.authority == authority.key() @ ErrorCode::Unauthorized
+    )]
+    pub verifier_state: Account<'info, VerifierState>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Replaces a registered circuit's verifying key in place (e.g. after a
+/// trusted setup ceremony is redone). The account was sized for
+/// `MAX_PUBLIC_INPUTS` at registration time, so no reallocation is needed.
+pub(crate) fn handler(
+    ctx: Context<UpdateCircuit>,
+    _circuit_id: String,
+    alpha_g1: [u8; G1_SIZE],
+    beta_g2: [u8; G2_SIZE],
+    gamma_g2: [u8; G2_SIZE],
+    delta_g2: [u8; G2_SIZE],
+    ic: Vec<[u8; G1_SIZE]>,
+) -> Result<()> {
+    require!(!ic.is_empty(), ErrorCode::InvalidVerifyingKey);
+    require!(
+        ic.len() <= MAX_PUBLIC_INPUTS + 1,
+        ErrorCode::TooManyPublicInputs
+    );
+
+    let verifying_key = &mut ctx.accounts.verifying_key;
+    verifying_key.alpha_g1 = alpha_g1;
+    verifying_key.beta_g2 = beta_g2;
+    verifying_key.gamma_g2 = gamma_g2;
+    verifying_key.delta_g2 = delta_g2;
+    verifying_key.ic = ic;
+
+    msg!("Circuit updated: {}", verifying_key.circuit_id);
+
+    Ok(())
+}
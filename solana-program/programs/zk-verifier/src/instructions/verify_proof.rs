@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FR_SIZE, G1_SIZE, G2_SIZE};
+use crate::crypto::{check_groth16_pairing, compute_vk_x, hash_public_inputs};
+use crate::errors::ErrorCode;
+use crate::pda::create_and_write_pda;
+use crate::state::{
+    NullifierAccount, VerificationAccount, VerificationOutcome, VerifierState, VerifyingKeyAccount,
+};
+
+#[derive(Accounts)]
+#[instruction(proof_hash: [u8; 32], circuit_id: String, nullifier: [u8; FR_SIZE])]
+pub struct VerifyProof<'info> {
+    #[account(
+        init,
+        payer = verifier,
+        space = 8 + VerificationAccount::INIT_SPACE,
+        seeds = [b"verification", proof_hash.as_ref()],
+        bump
+    )]
+    pub verification_account: Account<'info, VerificationAccount>,
+
+    #[account(
+        seeds = [b"vk", circuit_id.as_bytes()],
+        bump = verifying_key.bump,
+    )]
+    pub verifying_key: Account<'info, VerifyingKeyAccount>,
+
+    /// CHECK: only created (via CPI) once the proof is verified and the
+    /// nullifier hasn't been spent yet; empty otherwise.
+    #[account(
+        mut,
+        seeds = [b"nullifier", circuit_id.as_bytes(), nullifier.as_ref()],
+        bump
+    )]
+    pub nullifier_account: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier-state"],
+        bump
+    )]
+    pub verifier_state: Account<'info, VerifierState>,
+
+    #[account(mut)]
+    pub verifier: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Verify a Groth16 ZK-SNARK proof on-chain against a registered circuit's
+/// verifying key, using Solana's alt_bn128 syscalls.
+///
+/// Checks the pairing equation
+/// `e(A,B) * e(-vk_x,gamma) * e(-C,delta) * e(-alpha,beta) == 1`
+/// where `vk_x = ic[0] + sum_i public_inputs[i] * ic[i+1]`, computed via
+/// `sol_alt_bn128_group_op` (G1 add / scalar-mul), with the final product
+/// checked via a single `sol_alt_bn128_pairing` call.
+///
+/// A proof that is merely rejected (malformed, wrong input count, or a
+/// failing pairing) still records a `VerificationAccount` carrying the
+/// matching `VerificationOutcome`, rather than aborting the instruction, so
+/// callers can distinguish *why* a proof wasn't accepted instead of treating
+/// every rejection the same. A real error (e.g. the circuit being
+/// deactivated, or the syscalls rejecting malformed point bytes) still
+/// aborts the instruction.
+///
+/// A proof that *does* verify spends `nullifier`, which must equal
+/// `public_inputs[0]` (the circuit's designated commitment/public-signal
+/// field): the first time a given nullifier is spent for `circuit_id`, this
+/// creates its `NullifierAccount`; a second attempt fails with
+/// `ErrorCode::NullifierAlreadyUsed` because the PDA already exists.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn handler(
+    ctx: Context<VerifyProof>,
+    proof_hash: [u8; 32],
+    circuit_id: String,
+    nullifier: [u8; FR_SIZE],
+    proof_a: [u8; G1_SIZE],
+    proof_b: [u8; G2_SIZE],
+    proof_c: [u8; G1_SIZE],
+    public_inputs: Vec<[u8; FR_SIZE]>,
+) -> Result<()> {
+    let verifying_key = &ctx.accounts.verifying_key;
+    require!(verifying_key.active, ErrorCode::CircuitInactive);
+
+    let outcome = if public_inputs.is_empty() {
+        VerificationOutcome::ProofMalformed
+    } else if verifying_key.ic.len() != public_inputs.len() + 1 {
+        VerificationOutcome::PublicInputMismatch
+    } else {
+        let vk_x = compute_vk_x(&verifying_key.ic, &public_inputs)?;
+        let pairing_ok = check_groth16_pairing(
+            &proof_a,
+            &proof_b,
+            &vk_x,
+            &verifying_key.gamma_g2,
+            &proof_c,
+            &verifying_key.delta_g2,
+            &verifying_key.alpha_g1,
+            &verifying_key.beta_g2,
+        )?;
+        if pairing_ok {
+            VerificationOutcome::Verified
+        } else {
+            VerificationOutcome::PairingFailed
+        }
+    };
+
+    let verification_account = &mut ctx.accounts.verification_account;
+    verification_account.proof_hash = proof_hash;
+    verification_account.verifier = ctx.accounts.verifier.key();
+    verification_account.public_inputs_hash = hash_public_inputs(&public_inputs);
+    verification_account.outcome = outcome;
+    verification_account.verified = outcome == VerificationOutcome::Verified;
+    verification_account.timestamp = Clock::get()?.unix_timestamp;
+    verification_account.guardian_epoch = ctx.accounts.verifier_state.current_guardian_epoch;
+    verification_account.signed_guardians = 0;
+    verification_account.finalized = false;
+    verification_account.bump = ctx.bumps.verification_account;
+
+    if verification_account.verified {
+        ctx.accounts.verifier_state.total_verifications += 1;
+    }
+
+    if outcome == VerificationOutcome::Verified {
+        require!(
+            public_inputs[0] == nullifier,
+            ErrorCode::NullifierMismatch
+        );
+        require!(
+            ctx.accounts.nullifier_account.data_is_empty(),
+            ErrorCode::NullifierAlreadyUsed
+        );
+
+        let verification_key = ctx.accounts.verification_account.key();
+        create_and_write_pda(
+            &ctx.accounts.verifier.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.nullifier_account.to_account_info(),
+            ctx.program_id,
+            &[
+                b"nullifier",
+                circuit_id.as_bytes(),
+                nullifier.as_ref(),
+                &[ctx.bumps.nullifier_account],
+            ],
+            &NullifierAccount {
+                nullifier,
+                verification: verification_key,
+                timestamp: Clock::get()?.unix_timestamp,
+                bump: ctx.bumps.nullifier_account,
+            },
+        )?;
+    }
+
+    msg!("Proof outcome: hash={:?}, outcome={:?}", proof_hash, outcome);
+
+    Ok(())
+}
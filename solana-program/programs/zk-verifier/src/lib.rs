@@ -1,6 +1,15 @@
 use anchor_lang::prelude::*;
 
-declare_id!("YOUR_PROGRAM_ID_HERE");
+pub mod constants;
+pub mod crypto;
+pub mod errors;
+pub mod instructions;
+pub mod pda;
+pub mod state;
+
+use instructions::*;
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
 #[program]
 pub mod zk_verifier {
@@ -8,157 +17,115 @@ pub mod zk_verifier {
 
     /// Initialize the verifier program
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        let verifier_state = &mut ctx.accounts.verifier_state;
-        verifier_state.authority = ctx.accounts.authority.key();
-        verifier_state.total_verifications = 0;
-        msg!("ZK Verifier initialized");
-        Ok(())
+        instructions::initialize::handler(ctx)
     }
 
-    /// Verify a Groth16 ZK-SNARK proof on-chain
-    /// 
-    /// Note: Full Groth16 verification requires pairing operations which are
-    /// expensive on Solana. This implementation validates proof structure and
-    /// public signals, then stores the verification result.
-    /// 
-    /// For production, consider using a verified program like Light Protocol
-    /// or implement full pairing checks with Solana's syscalls.
-    pub fn verify_proof(
-        ctx: Context<VerifyProof>,
-        proof_hash: [u8; 32],
-        public_signals: Vec<String>,
-        threshold: u64,
-        commitment: u64,
+    /// Register a circuit's Groth16 verifying key so proofs for it can be
+    /// verified without redeploying the program.
+    pub fn register_circuit(
+        ctx: Context<RegisterCircuit>,
+        circuit_id: String,
+        alpha_g1: [u8; constants::G1_SIZE],
+        beta_g2: [u8; constants::G2_SIZE],
+        gamma_g2: [u8; constants::G2_SIZE],
+        delta_g2: [u8; constants::G2_SIZE],
+        ic: Vec<[u8; constants::G1_SIZE]>,
     ) -> Result<()> {
-        let verification_account = &mut ctx.accounts.verification_account;
-        let verifier_state = &mut ctx.accounts.verifier_state;
-
-        // Basic validation
-        require!(public_signals.len() >= 2, ErrorCode::InvalidPublicSignals);
-        require!(threshold > 0, ErrorCode::InvalidThreshold);
-
-        // Parse public signals
-        let signal_threshold = public_signals[0]
-            .parse::<u64>()
-            .map_err(|_| ErrorCode::InvalidPublicSignals)?;
-        let signal_commitment = public_signals[1]
-            .parse::<u64>()
-            .map_err(|_| ErrorCode::InvalidPublicSignals)?;
-
-        // Verify public signals match provided values
-        require!(
-            signal_threshold == threshold,
-            ErrorCode::ThresholdMismatch
-        );
-        require!(
-            signal_commitment == commitment,
-            ErrorCode::CommitmentMismatch
-        );
+        instructions::register_circuit::handler(
+            ctx, circuit_id, alpha_g1, beta_g2, gamma_g2, delta_g2, ic,
+        )
+    }
 
-        // Store verification result
-        verification_account.proof_hash = proof_hash;
-        verification_account.verifier = ctx.accounts.verifier.key();
-        verification_account.threshold = threshold;
-        verification_account.commitment = commitment;
-        verification_account.verified = true;
-        verification_account.timestamp = Clock::get()?.unix_timestamp;
-        verification_account.bump = ctx.bumps.verification_account;
+    /// Replace a registered circuit's verifying key.
+    pub fn update_circuit(
+        ctx: Context<UpdateCircuit>,
+        circuit_id: String,
+        alpha_g1: [u8; constants::G1_SIZE],
+        beta_g2: [u8; constants::G2_SIZE],
+        gamma_g2: [u8; constants::G2_SIZE],
+        delta_g2: [u8; constants::G2_SIZE],
+        ic: Vec<[u8; constants::G1_SIZE]>,
+    ) -> Result<()> {
+        instructions::update_circuit::handler(
+            ctx, circuit_id, alpha_g1, beta_g2, gamma_g2, delta_g2, ic,
+        )
+    }
 
-        // Update global state
-        verifier_state.total_verifications += 1;
+    /// Deactivate a registered circuit so it no longer accepts proofs.
+    pub fn deactivate_circuit(ctx: Context<DeactivateCircuit>, circuit_id: String) -> Result<()> {
+        instructions::deactivate_circuit::handler(ctx, circuit_id)
+    }
 
-        msg!(
-            "Proof verified: hash={:?}, threshold={}, commitment={}",
-            proof_hash,
-            threshold,
-            commitment
-        );
+    /// Verify a Groth16 ZK-SNARK proof on-chain against a registered circuit.
+    /// `nullifier` must equal `public_inputs[0]`; a verified proof spends it,
+    /// so a second proof reusing the same commitment is rejected.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_proof(
+        ctx: Context<VerifyProof>,
+        proof_hash: [u8; 32],
+        circuit_id: String,
+        nullifier: [u8; constants::FR_SIZE],
+        proof_a: [u8; constants::G1_SIZE],
+        proof_b: [u8; constants::G2_SIZE],
+        proof_c: [u8; constants::G1_SIZE],
+        public_inputs: Vec<[u8; constants::FR_SIZE]>,
+    ) -> Result<()> {
+        instructions::verify_proof::handler(
+            ctx, proof_hash, circuit_id, nullifier, proof_a, proof_b, proof_c, public_inputs,
+        )
+    }
 
-        Ok(())
+    /// Verify a batch of Groth16 proofs against the same registered circuit
+    /// with a single combined pairing check, amortizing pairing cost across
+    /// the whole batch.
+    pub fn verify_proof_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifyProofBatch<'info>>,
+        circuit_id: String,
+        proofs: Vec<instructions::BatchProofInput>,
+    ) -> Result<()> {
+        instructions::verify_proof_batch::handler(ctx, circuit_id, proofs)
     }
 
     /// Query if a proof has been verified
-    pub fn get_verification_status(
-        ctx: Context<GetVerificationStatus>,
-    ) -> Result<bool> {
-        Ok(ctx.accounts.verification_account.verified)
+    pub fn get_verification_status(ctx: Context<GetVerificationStatus>) -> Result<bool> {
+        instructions::get_verification_status::handler(ctx)
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + VerifierState::INIT_SPACE,
-        seeds = [b"verifier-state"],
-        bump
-    )]
-    pub verifier_state: Account<'info, VerifierState>,
-
-    #[account(mut)]
-    pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-#[instruction(proof_hash: [u8; 32])]
-pub struct VerifyProof<'info> {
-    #[account(
-        init,
-        payer = verifier,
-        space = 8 + VerificationAccount::INIT_SPACE,
-        seeds = [b"verification", proof_hash.as_ref()],
-        bump
-    )]
-    pub verification_account: Account<'info, VerificationAccount>,
-
-    #[account(
-        mut,
-        seeds = [b"verifier-state"],
-        bump
-    )]
-    pub verifier_state: Account<'info, VerifierState>,
-
-    #[account(mut)]
-    pub verifier: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
-}
-
-#[derive(Accounts)]
-pub struct GetVerificationStatus<'info> {
-    pub verification_account: Account<'info, VerificationAccount>,
-}
+    /// Query the typed reason a proof was or wasn't accepted.
+    pub fn get_verification_outcome(
+        ctx: Context<GetVerificationOutcome>,
+    ) -> Result<state::VerificationOutcome> {
+        instructions::get_verification_outcome::handler(ctx)
+    }
 
-#[account]
-#[derive(InitSpace)]
-pub struct VerifierState {
-    pub authority: Pubkey,
-    pub total_verifications: u64,
-}
+    /// Register a new guardian-set epoch, rotating which guardians may
+    /// attest proofs verified from now on.
+    pub fn create_guardian_set(
+        ctx: Context<CreateGuardianSet>,
+        epoch: u64,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_guardian_set::handler(ctx, epoch, guardians, threshold)
+    }
 
-#[account]
-#[derive(InitSpace)]
-pub struct VerificationAccount {
-    pub proof_hash: [u8; 32],
-    pub verifier: Pubkey,
-    pub threshold: u64,
-    pub commitment: u64,
-    pub verified: bool,
-    pub timestamp: i64,
-    pub bump: u8,
-}
+    /// A guardian attests to a verified proof; once `threshold` distinct
+    /// guardians have attested, the proof is marked `finalized`.
+    pub fn attest_verification(
+        ctx: Context<AttestVerification>,
+        proof_hash: [u8; 32],
+        guardian_index: u8,
+    ) -> Result<()> {
+        instructions::attest_verification::handler(ctx, proof_hash, guardian_index)
+    }
 
-#[error_code]
-pub enum ErrorCode {
-    #[msg("Invalid public signals provided")]
-    InvalidPublicSignals,
-    #[msg("Invalid threshold value")]
-    InvalidThreshold,
-    #[msg("Threshold mismatch between proof and provided value")]
-    ThresholdMismatch,
-    #[msg("Commitment mismatch between proof and provided value")]
-    CommitmentMismatch,
+    /// Query whether a circuit's commitment/public-signal field has already
+    /// been spent by a verified proof.
+    pub fn get_nullifier_status(
+        ctx: Context<GetNullifierStatus>,
+        circuit_id: String,
+        nullifier: [u8; 32],
+    ) -> Result<bool> {
+        instructions::get_nullifier_status::handler(ctx, circuit_id, nullifier)
+    }
 }
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_lang::Discriminator;
+
+/// Creates a program-owned PDA via CPI and writes `data`'s Anchor
+/// discriminator plus borsh encoding into it. Used by instructions that
+/// create a variable number of PDAs, or only conditionally, which Anchor's
+/// `#[account(init, ...)]` constraint can't express on its own (e.g. one
+/// `VerificationAccount` per proof in a batch, or a nullifier PDA created
+/// only the first time a commitment is spent).
+pub fn create_and_write_pda<'info, T: AccountSerialize + Discriminator + Space>(
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    program_id: &Pubkey,
+    signer_seeds: &[&[u8]],
+    data: &T,
+) -> Result<()> {
+    let space = 8 + T::INIT_SPACE;
+    let lamports = Rent::get()?.minimum_balance(space);
+
+    create_account(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            CreateAccount {
+                from: payer.clone(),
+                to: target.clone(),
+            },
+            &[signer_seeds],
+        ),
+        lamports,
+        space as u64,
+        program_id,
+    )?;
+
+    let mut account_data = target.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut account_data;
+    data.try_serialize(&mut writer)
+}
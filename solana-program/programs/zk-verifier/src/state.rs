@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{
+    FR_SIZE, G1_SIZE, G2_SIZE, MAX_CIRCUIT_ID_LEN, MAX_GUARDIANS, MAX_PUBLIC_INPUTS,
+};
+
+#[account]
+#[derive(InitSpace)]
+pub struct VerifierState {
+    pub authority: Pubkey,
+    pub total_verifications: u64,
+    /// Epoch of the most recently registered `GuardianSet`, or 0 if none has
+    /// been created yet. Proofs verified while an epoch is active record it
+    /// so `attest_verification` knows which guardian set to check against.
+    pub current_guardian_epoch: u64,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VerificationAccount {
+    pub proof_hash: [u8; 32],
+    pub verifier: Pubkey,
+    pub public_inputs_hash: [u8; FR_SIZE],
+    pub verified: bool,
+    pub outcome: VerificationOutcome,
+    pub timestamp: i64,
+    /// Guardian-set epoch active when this proof was verified; 0 means no
+    /// guardian set existed yet, so attestation doesn't apply.
+    pub guardian_epoch: u64,
+    /// Bitmask of guardian indices (within the epoch's `GuardianSet`) that
+    /// have attested this proof.
+    pub signed_guardians: u32,
+    /// Set once at least `threshold` distinct guardians have attested.
+    pub finalized: bool,
+    pub bump: u8,
+}
+
+/// Why a proof was or wasn't accepted, distinct from the `verified` bool so
+/// callers can tell a malformed submission from a cryptographic failure
+/// instead of collapsing every rejection reason into `false`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum VerificationOutcome {
+    /// No verification has been recorded for this outcome yet.
+    #[default]
+    Unknown,
+    /// The pairing check passed against the registered verifying key.
+    Verified,
+    /// The proof submission was structurally invalid (e.g. no public inputs).
+    ProofMalformed,
+    /// The number of public inputs doesn't match the registered circuit.
+    PublicInputMismatch,
+    /// The proof was well-formed but the Groth16 pairing check failed.
+    PairingFailed,
+}
+
+/// A registered Groth16 verifying key for one circuit, letting a single
+/// deployment serve verification for many distinct applications.
+#[account]
+#[derive(InitSpace)]
+pub struct VerifyingKeyAccount {
+    #[max_len(MAX_CIRCUIT_ID_LEN)]
+    pub circuit_id: String,
+    pub authority: Pubkey,
+    pub alpha_g1: [u8; G1_SIZE],
+    pub beta_g2: [u8; G2_SIZE],
+    pub gamma_g2: [u8; G2_SIZE],
+    pub delta_g2: [u8; G2_SIZE],
+    #[max_len(MAX_PUBLIC_INPUTS + 1)]
+    pub ic: Vec<[u8; G1_SIZE]>,
+    pub active: bool,
+    pub bump: u8,
+}
+
+/// Marks a circuit's commitment/public-signal field as spent so the same
+/// proof (or a distinct proof reusing the same commitment) can't be used
+/// twice for one-time access or posting rights. Seeded by `circuit_id` and
+/// the nullifier value so different circuits never collide.
+#[account]
+#[derive(InitSpace)]
+pub struct NullifierAccount {
+    pub nullifier: [u8; FR_SIZE],
+    pub verification: Pubkey,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// An M-of-N set of guardians authorized to co-sign verified proofs before
+/// they're considered final, borrowing the guardian/multisig attestation
+/// model used for cross-chain message verification. Rotated by registering a
+/// new epoch rather than mutating one in place, so past attestations keep
+/// referring to the guardian set that was active when they were made.
+#[account]
+#[derive(InitSpace)]
+pub struct GuardianSet {
+    pub epoch: u64,
+    pub threshold: u8,
+    #[max_len(MAX_GUARDIANS)]
+    pub guardians: Vec<Pubkey>,
+    pub authority: Pubkey,
+    pub bump: u8,
+}